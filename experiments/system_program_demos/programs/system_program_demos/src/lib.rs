@@ -38,6 +38,153 @@ pub mod system_program_demos {
         msg!("Account created successfully!");
         Ok(())
     }
+
+    pub fn transfer(ctx: Context<Transfer>, amount: u64) -> Result<()> {
+        msg!("Transferring {} lamports", amount);
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.from.to_account_info(),
+                    to: ctx.accounts.to.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        msg!("Transfer complete!");
+        Ok(())
+    }
+
+    pub fn allocate(ctx: Context<Allocate>, space: u64) -> Result<()> {
+        msg!("Allocating {} bytes of space", space);
+
+        system_program::allocate(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Allocate {
+                    account_to_allocate: ctx.accounts.allocated_account.to_account_info(),
+                },
+            ),
+            space,
+        )?;
+
+        msg!("Allocation complete!");
+        Ok(())
+    }
+
+    pub fn assign(ctx: Context<Assign>, owner: Pubkey) -> Result<()> {
+        msg!("Assigning account to new owner: {:?}", owner);
+
+        system_program::assign(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Assign {
+                    account_to_assign: ctx.accounts.assigned_account.to_account_info(),
+                },
+            ),
+            &owner,
+        )?;
+
+        msg!("Assignment complete!");
+        Ok(())
+    }
+
+    pub fn transfer_with_seed(
+        ctx: Context<TransferWithSeed>,
+        amount: u64,
+        seed: String,
+        owner: Pubkey,
+    ) -> Result<()> {
+        msg!("Transferring {} lamports from a seeded account", amount);
+
+        system_program::transfer_with_seed(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::TransferWithSeed {
+                    from: ctx.accounts.from.to_account_info(),
+                    base: ctx.accounts.base.to_account_info(),
+                    to: ctx.accounts.to.to_account_info(),
+                },
+            ),
+            amount,
+            seed,
+            &owner,
+        )?;
+
+        msg!("Transfer complete!");
+        Ok(())
+    }
+
+    pub fn create_account_declarative(
+        ctx: Context<CreateAccountDeclarative>,
+        owner: Pubkey,
+        label: String,
+    ) -> Result<()> {
+        require!(label.len() <= 32, DemoError::LabelTooLong);
+
+        let demo_data = &mut ctx.accounts.new_account;
+        demo_data.owner = owner;
+        demo_data.counter = 0;
+        demo_data.label = label;
+
+        msg!(
+            "Declaratively created account: {:?}",
+            ctx.accounts.new_account.key()
+        );
+        Ok(())
+    }
+
+    pub fn create(ctx: Context<CreateCounter>, authority: Pubkey) -> Result<()> {
+        let counter = &mut ctx.accounts.counter;
+        counter.authority = authority;
+        counter.count = 0;
+
+        msg!("Counter created with authority: {:?}", authority);
+        Ok(())
+    }
+
+    pub fn increment(ctx: Context<Increment>) -> Result<()> {
+        let counter = &mut ctx.accounts.counter;
+        counter.count = counter.count.checked_add(1).ok_or(DemoError::Overflow)?;
+
+        msg!("Counter incremented to {}", counter.count);
+        Ok(())
+    }
+
+    pub fn create_pda(ctx: Context<CreatePda>, label: String) -> Result<()> {
+        require!(label.len() <= 32, DemoError::LabelTooLong);
+
+        let demo_data = &mut ctx.accounts.pda_account;
+        demo_data.owner = ctx.accounts.payer.key();
+        demo_data.counter = 0;
+        demo_data.label = label;
+
+        msg!("PDA account created: {:?}", ctx.accounts.pda_account.key());
+        Ok(())
+    }
+
+    pub fn reclaim_rent(ctx: Context<ReclaimRent>) -> Result<()> {
+        msg!(
+            "Closing {:?}, rent reclaimed to {:?}",
+            ctx.accounts.closing_account.key(),
+            ctx.accounts.destination.key()
+        );
+        Ok(())
+    }
+
+    pub fn inspect_account(ctx: Context<InspectAccount>) -> Result<()> {
+        let target = ctx.accounts.target.to_account_info();
+
+        msg!(
+            "Account {:?} is owned by program {:?} and holds {} lamports",
+            target.key(),
+            target.owner,
+            target.lamports()
+        );
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -47,9 +194,157 @@ pub struct Initialize {}
 pub struct CreateAccount<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    
+
     #[account(mut)]
     pub new_account: Signer<'info>,
-    
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Transfer<'info> {
+    #[account(mut)]
+    pub from: Signer<'info>,
+
+    #[account(mut)]
+    pub to: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Allocate<'info> {
+    #[account(mut)]
+    pub allocated_account: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Assign<'info> {
+    #[account(mut)]
+    pub assigned_account: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferWithSeed<'info> {
+    #[account(mut)]
+    pub from: SystemAccount<'info>,
+
+    pub base: Signer<'info>,
+
+    #[account(mut)]
+    pub to: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateAccountDeclarative<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(init, payer = payer, space = 8 + DemoData::INIT_SPACE)]
+    pub new_account: Account<'info, DemoData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Demonstrates the typed wrappers Anchor offers for accounts that
+/// actually need to be checked:
+/// - `closing_account` is `Account<DemoData>` (deserialized + owner-checked)
+///   because `has_one = owner` needs to read its `owner` field and compare
+///   it against the signer below. The `close = destination` constraint
+///   transfers its lamports to `destination` *and* zeroes its discriminator,
+///   which is what actually prevents the classic close/revival exploit that
+///   a hand-rolled lamport drain leaves open.
+/// - `owner` is a bare `Signer` since we only need proof of its signature,
+///   never its data, to authorize the close.
+/// - `destination` is `SystemAccount` because it's merely a lamport sink:
+///   we need proof it's a plain wallet, not a signature or its data.
+#[derive(Accounts)]
+pub struct ReclaimRent<'info> {
+    #[account(mut, close = destination, has_one = owner)]
+    pub closing_account: Account<'info, DemoData>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+}
+
+/// Contrasts with `ReclaimRent` above: here the account genuinely doesn't
+/// need to be deserialized or owner-checked, because this instruction never
+/// trusts or mutates its contents — it only reads the public metadata
+/// (key, owning program, lamport balance) that every `AccountInfo` exposes
+/// regardless of who owns it. That's the one case where `UncheckedAccount`
+/// is the right typed wrapper rather than a shortcut around a missing check.
+#[derive(Accounts)]
+pub struct InspectAccount<'info> {
+    /// CHECK: never deserialized and no owner/signer check is needed —
+    /// only its key, owning program, and lamport balance are read, none of
+    /// which this instruction trusts or acts on privilegedly.
+    pub target: UncheckedAccount<'info>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DemoData {
+    pub owner: Pubkey,
+    pub counter: u64,
+    #[max_len(32)]
+    pub label: String,
+}
+
+#[derive(Accounts)]
+pub struct CreateCounter<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(init, payer = payer, space = 8 + Counter::INIT_SPACE)]
+    pub counter: Account<'info, Counter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Increment<'info> {
+    #[account(mut, has_one = authority)]
+    pub counter: Account<'info, Counter>,
+
+    pub authority: Signer<'info>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Counter {
+    pub authority: Pubkey,
+    pub count: u64,
+}
+
+#[error_code]
+pub enum DemoError {
+    #[msg("Counter overflowed")]
+    Overflow,
+    #[msg("Label exceeds the 32-byte max_len bound")]
+    LabelTooLong,
+}
+
+#[derive(Accounts)]
+pub struct CreatePda<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DemoData::INIT_SPACE,
+        seeds = [b"demo", payer.key().as_ref()],
+        bump,
+    )]
+    pub pda_account: Account<'info, DemoData>,
+
     pub system_program: Program<'info, System>,
 }